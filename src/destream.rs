@@ -1,10 +1,11 @@
+use std::collections::BTreeMap;
 use std::str::FromStr;
 
 use async_trait::async_trait;
 use destream::de::{self, Decoder, FromStream};
 use destream::en::{Encoder, IntoStream, ToStream};
 
-use super::Id;
+use super::{Id, Map, PathBuf, Tuple};
 
 #[async_trait]
 impl FromStream for Id {
@@ -27,3 +28,67 @@ impl<'en> IntoStream<'en> for Id {
         e.encode_str(self.as_str())
     }
 }
+
+#[async_trait]
+impl FromStream for PathBuf {
+    type Context = ();
+
+    async fn from_stream<D: Decoder>(cxt: (), decoder: &mut D) -> Result<Self, D::Error> {
+        let s = String::from_stream(cxt, decoder).await?;
+        Self::from_str(&s).map_err(de::Error::custom)
+    }
+}
+
+impl<'en> ToStream<'en> for PathBuf {
+    fn to_stream<E: Encoder<'en>>(&'en self, e: E) -> Result<E::Ok, E::Error> {
+        e.encode_str(&self.to_string())
+    }
+}
+
+impl<'en> IntoStream<'en> for PathBuf {
+    fn into_stream<E: Encoder<'en>>(self, e: E) -> Result<E::Ok, E::Error> {
+        e.encode_str(&self.to_string())
+    }
+}
+
+#[async_trait]
+impl<T: FromStream<Context = ()>> FromStream for Map<T> {
+    type Context = ();
+
+    async fn from_stream<D: Decoder>(cxt: (), decoder: &mut D) -> Result<Self, D::Error> {
+        BTreeMap::from_stream(cxt, decoder).await.map(Self::from)
+    }
+}
+
+impl<'en, T: ToStream<'en> + 'en> ToStream<'en> for Map<T> {
+    fn to_stream<E: Encoder<'en>>(&'en self, e: E) -> Result<E::Ok, E::Error> {
+        e.collect_map(self.iter())
+    }
+}
+
+impl<'en, T: IntoStream<'en> + 'en> IntoStream<'en> for Map<T> {
+    fn into_stream<E: Encoder<'en>>(self, e: E) -> Result<E::Ok, E::Error> {
+        e.collect_map(self.into_inner())
+    }
+}
+
+#[async_trait]
+impl<T: FromStream<Context = ()>> FromStream for Tuple<T> {
+    type Context = ();
+
+    async fn from_stream<D: Decoder>(cxt: (), decoder: &mut D) -> Result<Self, D::Error> {
+        Vec::from_stream(cxt, decoder).await.map(Self::from)
+    }
+}
+
+impl<'en, T: ToStream<'en> + 'en> ToStream<'en> for Tuple<T> {
+    fn to_stream<E: Encoder<'en>>(&'en self, e: E) -> Result<E::Ok, E::Error> {
+        e.collect_seq(self.iter())
+    }
+}
+
+impl<'en, T: IntoStream<'en> + 'en> IntoStream<'en> for Tuple<T> {
+    fn into_stream<E: Encoder<'en>>(self, e: E) -> Result<E::Ok, E::Error> {
+        e.collect_seq(self.into_inner())
+    }
+}
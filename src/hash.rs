@@ -1,7 +1,20 @@
 use async_hash::generic_array::{ArrayLength, GenericArray};
 use async_hash::{Digest, Hash, Output};
 
-use super::Id;
+use super::{Id, Map, Path, PathBuf, Tuple};
+
+impl<'a, D: Digest> Hash<D> for &'a Path {
+    fn hash(self) -> Output<D> {
+        // fold over the path's segments
+        Hash::<D>::hash(self.as_slice().iter().collect::<Vec<&Id>>())
+    }
+}
+
+impl<'a, D: Digest> Hash<D> for &'a PathBuf {
+    fn hash(self) -> Output<D> {
+        Hash::<D>::hash(self.as_slice().iter().collect::<Vec<&Id>>())
+    }
+}
 
 impl<D: Digest> Hash<D> for Id {
     fn hash(self) -> Output<D> {
@@ -15,6 +28,26 @@ impl<'a, D: Digest> Hash<D> for &'a Id {
     }
 }
 
+impl<'a, D: Digest, T> Hash<D> for &'a Map<T>
+where
+    &'a Id: Hash<D>,
+    &'a T: Hash<D>,
+{
+    fn hash(self) -> Output<D> {
+        // fold over entries in canonical (sorted) key order
+        Hash::<D>::hash(self.iter().collect::<Vec<(&'a Id, &'a T)>>())
+    }
+}
+
+impl<'a, D: Digest, T> Hash<D> for &'a Tuple<T>
+where
+    &'a T: Hash<D>,
+{
+    fn hash(self) -> Output<D> {
+        Hash::<D>::hash(self.iter().collect::<Vec<&'a T>>())
+    }
+}
+
 impl<T, U> From<GenericArray<T, U>> for Id
 where
     U: ArrayLength<T>,
@@ -37,8 +37,15 @@ use safecast::TryCastFrom;
 mod destream;
 #[cfg(feature = "hash")]
 mod hash;
+mod map;
+mod path;
 #[cfg(feature = "serde")]
 mod serde;
+mod tuple;
+
+pub use map::Map;
+pub use path::{path_label, Path, PathBuf, PathLabel, PathSegment};
+pub use tuple::Tuple;
 
 /// A set of prohibited character patterns.
 pub const RESERVED_CHARS: [&str; 21] = [
@@ -104,6 +111,161 @@ pub const fn label(id: &'static str) -> Label {
     Label { id }
 }
 
+/// A validation profile which defines the set of characters admissible in an [`Id`].
+///
+/// Inspired by the way ASN.1 distinguishes string types (`IA5String`, `PrintableString`,
+/// `NumericString`), each with its own admissible character set.
+pub trait Validator {
+    /// The human-readable name of this profile, used in [`ParseError`] messages.
+    const NAME: &'static str;
+
+    /// Validate `id` under this profile, returning a [`ParseError`] naming the profile
+    /// and, where a single character or pattern is at fault, that character on failure.
+    fn validate(id: &str) -> Result<(), ParseError>;
+}
+
+/// The standard validation profile: no control characters, no whitespace, and no
+/// [`RESERVED_CHARS`]. This is the profile used by [`Id`]'s [`FromStr`] impl.
+pub struct Standard;
+
+impl Validator for Standard {
+    const NAME: &'static str = "Standard";
+
+    fn validate(id: &str) -> Result<(), ParseError> {
+        if id.is_empty() {
+            return Err(empty_id(Self::NAME));
+        }
+
+        let mut invalid_chars = id.chars().filter(|c| (*c as u8) < 32u8);
+        if let Some(invalid) = invalid_chars.next() {
+            return Err(format!(
+                "Id {} contains ASCII control characters {} which are not allowed by the {} profile",
+                id,
+                invalid as u8,
+                Self::NAME,
+            )
+            .into());
+        }
+
+        for pattern in &RESERVED_CHARS {
+            if id.contains(pattern) {
+                return Err(format!(
+                    "Id {} contains disallowed pattern {} in the {} profile",
+                    id,
+                    pattern,
+                    Self::NAME,
+                )
+                .into());
+            }
+        }
+
+        if let Some(w) = Regex::new(r"\s").expect("whitespace regex").find(id) {
+            return Err(format!(
+                "Id {} is not allowed to contain whitespace {:?} in the {} profile",
+                id,
+                w,
+                Self::NAME,
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+}
+
+/// A DNS label profile: ASCII alphanumeric characters and hyphens, with no leading or
+/// trailing hyphen, up to 63 bytes in length.
+pub struct DnsLabel;
+
+impl Validator for DnsLabel {
+    const NAME: &'static str = "DnsLabel";
+
+    fn validate(id: &str) -> Result<(), ParseError> {
+        if id.is_empty() {
+            return Err(empty_id(Self::NAME));
+        }
+
+        if id.len() > 63 {
+            return Err(format!(
+                "Id {} is too long for the {} profile (max 63 bytes)",
+                id,
+                Self::NAME,
+            )
+            .into());
+        }
+
+        if id.starts_with('-') || id.ends_with('-') {
+            return Err(format!(
+                "Id {} must not begin or end with a hyphen in the {} profile",
+                id,
+                Self::NAME,
+            )
+            .into());
+        }
+
+        if let Some(invalid) = id
+            .chars()
+            .find(|c| !(c.is_ascii_alphanumeric() || *c == '-'))
+        {
+            return Err(invalid_char(Self::NAME, id, invalid));
+        }
+
+        Ok(())
+    }
+}
+
+/// The ASN.1 `PrintableString` profile: letters, digits, space, and `'()+,-./:=?`.
+pub struct Printable;
+
+impl Validator for Printable {
+    const NAME: &'static str = "Printable";
+
+    fn validate(id: &str) -> Result<(), ParseError> {
+        if id.is_empty() {
+            return Err(empty_id(Self::NAME));
+        }
+
+        if let Some(invalid) = id.chars().find(|c| {
+            !(c.is_ascii_alphanumeric() || matches!(c, ' ' | '\'' | '(' | ')' | '+' | ',' | '-' | '.' | '/' | ':' | '=' | '?'))
+        }) {
+            return Err(invalid_char(Self::NAME, id, invalid));
+        }
+
+        Ok(())
+    }
+}
+
+/// The ASN.1 `NumericString` profile: digits and space.
+pub struct Numeric;
+
+impl Validator for Numeric {
+    const NAME: &'static str = "Numeric";
+
+    fn validate(id: &str) -> Result<(), ParseError> {
+        if id.is_empty() {
+            return Err(empty_id(Self::NAME));
+        }
+
+        if let Some(invalid) = id.chars().find(|c| !(c.is_ascii_digit() || *c == ' ')) {
+            return Err(invalid_char(Self::NAME, id, invalid));
+        }
+
+        Ok(())
+    }
+}
+
+fn empty_id(profile: &str) -> ParseError {
+    format!("cannot construct an empty Id in the {} profile", profile).into()
+}
+
+fn invalid_char(profile: &str, id: &str, invalid: char) -> ParseError {
+    format!(
+        "Id {} contains character {:?} which is not allowed by the {} profile",
+        id, invalid, profile,
+    )
+    .into()
+}
+
 /// A human-readable ID
 #[derive(Clone, Eq, Hash, PartialEq, Ord, PartialOrd)]
 pub struct Id {
@@ -117,6 +279,12 @@ impl Id {
         self.inner.as_ref()
     }
 
+    /// Parse an [`Id`] from `s` using the given [`Validator`] profile `P`.
+    pub fn parse_with<P: Validator>(s: &str) -> Result<Self, ParseError> {
+        P::validate(s)?;
+        Ok(Id { inner: s.into() })
+    }
+
     /// Destructure this [`Id`] into its inner `Arc<str>`.
     pub fn into_inner(self) -> Arc<str> {
         self.inner
@@ -253,28 +421,5 @@ impl fmt::Display for Id {
 }
 
 fn validate_id(id: &str) -> Result<(), ParseError> {
-    if id.is_empty() {
-        return Err("cannot construct an empty Id".into());
-    }
-
-    let mut invalid_chars = id.chars().filter(|c| (*c as u8) < 32u8);
-    if let Some(invalid) = invalid_chars.next() {
-        return Err(format!(
-            "Id {} contains ASCII control characters {}",
-            id, invalid as u8,
-        )
-        .into());
-    }
-
-    for pattern in &RESERVED_CHARS {
-        if id.contains(pattern) {
-            return Err(format!("Id {} contains disallowed pattern {}", id, pattern).into());
-        }
-    }
-
-    if let Some(w) = Regex::new(r"\s").expect("whitespace regex").find(id) {
-        return Err(format!("Id {} is not allowed to contain whitespace {:?}", id, w).into());
-    }
-
-    Ok(())
+    Standard::validate(id)
 }
@@ -0,0 +1,119 @@
+//! A generic map keyed by [`Id`].
+
+use std::collections::{btree_map, BTreeMap};
+use std::fmt;
+use std::iter::FromIterator;
+use std::mem::size_of;
+use std::ops::{Deref, DerefMut};
+
+use get_size::GetSize;
+
+use super::Id;
+
+/// A map from [`Id`] to `T`, ordered by key.
+///
+/// Iteration, encoding, and hashing all proceed in canonical (sorted) key order, so the
+/// same set of entries always produces the same byte stream and the same hash.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Map<T> {
+    inner: BTreeMap<Id, T>,
+}
+
+impl<T> Map<T> {
+    /// Construct a new, empty [`Map`].
+    pub fn new() -> Self {
+        Self {
+            inner: BTreeMap::new(),
+        }
+    }
+
+    /// Consume this [`Map`] and return the underlying [`BTreeMap`].
+    pub fn into_inner(self) -> BTreeMap<Id, T> {
+        self.inner
+    }
+}
+
+impl<T> Default for Map<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Deref for Map<T> {
+    type Target = BTreeMap<Id, T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> DerefMut for Map<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<T: GetSize> GetSize for Map<T> {
+    fn get_size(&self) -> usize {
+        size_of::<BTreeMap<Id, T>>()
+            + self
+                .inner
+                .iter()
+                .map(|(id, value)| id.get_size() + value.get_size())
+                .sum::<usize>()
+    }
+}
+
+impl<T> From<BTreeMap<Id, T>> for Map<T> {
+    fn from(inner: BTreeMap<Id, T>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T> FromIterator<(Id, T)> for Map<T> {
+    fn from_iter<I: IntoIterator<Item = (Id, T)>>(iter: I) -> Self {
+        Self {
+            inner: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> IntoIterator for Map<T> {
+    type Item = (Id, T);
+    type IntoIter = btree_map::IntoIter<Id, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Map<T> {
+    type Item = (&'a Id, &'a T);
+    type IntoIter = btree_map::Iter<'a, Id, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
+
+impl<T: fmt::Display> fmt::Debug for Map<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Map<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("{")?;
+
+        for (i, (id, value)) in self.inner.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+
+            write!(f, "{}: {}", id, value)?;
+        }
+
+        f.write_str("}")
+    }
+}
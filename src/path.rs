@@ -0,0 +1,262 @@
+//! A URI path composed of [`Id`] segments.
+
+use std::borrow::Borrow;
+use std::fmt;
+use std::mem::size_of;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use get_size::GetSize;
+
+use super::{Id, ParseError};
+
+/// A segment of a [`Path`], i.e. a single [`Id`].
+pub type PathSegment = Id;
+
+/// A borrowed slice of a [`PathBuf`].
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[repr(transparent)]
+pub struct Path {
+    segments: [PathSegment],
+}
+
+impl Path {
+    /// Return `true` if this path has no segments.
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Return the number of segments in this path.
+    pub fn len(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Return `true` if this path begins with the given `prefix`.
+    pub fn starts_with(&self, prefix: &Path) -> bool {
+        if prefix.len() > self.len() {
+            return false;
+        }
+
+        self.segments[..prefix.len()] == prefix.segments
+    }
+
+    /// Borrow the suffix of this path beginning at the given index.
+    ///
+    /// Panics if `start` is greater than the number of segments.
+    pub fn slice_from(&self, start: usize) -> &Path {
+        Self::from_slice(&self.segments[start..])
+    }
+
+    /// Borrow the segments of this path.
+    pub fn as_slice(&self) -> &[PathSegment] {
+        &self.segments
+    }
+
+    fn from_slice(segments: &[PathSegment]) -> &Self {
+        // SAFETY: `Path` is a `repr(transparent)` wrapper around `[PathSegment]`.
+        unsafe { &*(segments as *const [PathSegment] as *const Path) }
+    }
+}
+
+impl AsRef<[PathSegment]> for Path {
+    fn as_ref(&self) -> &[PathSegment] {
+        &self.segments
+    }
+}
+
+impl<'a> From<&'a Path> for PathBuf {
+    fn from(path: &'a Path) -> Self {
+        Self {
+            segments: path.segments.to_vec(),
+        }
+    }
+}
+
+impl ToOwned for Path {
+    type Owned = PathBuf;
+
+    fn to_owned(&self) -> PathBuf {
+        PathBuf {
+            segments: self.segments.to_vec(),
+        }
+    }
+}
+
+impl fmt::Debug for Path {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Display for Path {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for segment in &self.segments {
+            write!(f, "/{}", segment)?;
+        }
+
+        if self.segments.is_empty() {
+            f.write_str("/")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An owned, mutable URI path, made up of [`Id`] segments.
+#[derive(Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct PathBuf {
+    segments: Vec<PathSegment>,
+}
+
+impl PathBuf {
+    /// Construct a new, empty [`PathBuf`].
+    pub fn new() -> Self {
+        Self {
+            segments: Vec::new(),
+        }
+    }
+
+    /// Append a segment to the end of this path.
+    pub fn push(&mut self, segment: PathSegment) {
+        self.segments.push(segment);
+    }
+
+    /// Remove and return the last segment of this path, if any.
+    pub fn pop(&mut self) -> Option<PathSegment> {
+        self.segments.pop()
+    }
+
+    /// Append `suffix` to this path and return the result.
+    pub fn join(mut self, suffix: &Path) -> Self {
+        self.segments.extend_from_slice(&suffix.segments);
+        self
+    }
+
+    /// Consume this [`PathBuf`] and return its segments.
+    pub fn into_inner(self) -> Vec<PathSegment> {
+        self.segments
+    }
+}
+
+impl Borrow<Path> for PathBuf {
+    fn borrow(&self) -> &Path {
+        Path::from_slice(&self.segments)
+    }
+}
+
+impl Deref for PathBuf {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        Path::from_slice(&self.segments)
+    }
+}
+
+impl AsRef<Path> for PathBuf {
+    fn as_ref(&self) -> &Path {
+        self
+    }
+}
+
+impl Extend<PathSegment> for PathBuf {
+    fn extend<I: IntoIterator<Item = PathSegment>>(&mut self, iter: I) {
+        self.segments.extend(iter)
+    }
+}
+
+impl FromIterator<PathSegment> for PathBuf {
+    fn from_iter<I: IntoIterator<Item = PathSegment>>(iter: I) -> Self {
+        Self {
+            segments: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl From<PathSegment> for PathBuf {
+    fn from(segment: PathSegment) -> Self {
+        Self {
+            segments: vec![segment],
+        }
+    }
+}
+
+impl From<Vec<PathSegment>> for PathBuf {
+    fn from(segments: Vec<PathSegment>) -> Self {
+        Self { segments }
+    }
+}
+
+impl FromStr for PathBuf {
+    type Err = ParseError;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        if path.is_empty() {
+            return Err("cannot construct a Path from an empty string".into());
+        }
+
+        if !path.starts_with('/') {
+            return Err(format!("a Path must begin with a slash, not {}", path).into());
+        }
+
+        if path == "/" {
+            return Ok(Self::new());
+        }
+
+        if path.ends_with('/') {
+            return Err(format!("a Path must not end with a slash: {}", path).into());
+        }
+
+        path[1..]
+            .split('/')
+            .map(|segment| {
+                if segment.is_empty() {
+                    Err("a Path must not contain an empty segment".into())
+                } else {
+                    segment.parse()
+                }
+            })
+            .collect()
+    }
+}
+
+impl GetSize for PathBuf {
+    fn get_size(&self) -> usize {
+        size_of::<Vec<PathSegment>>()
+            + self.segments.iter().map(GetSize::get_size).sum::<usize>()
+    }
+}
+
+impl fmt::Debug for PathBuf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Display for PathBuf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.deref(), f)
+    }
+}
+
+/// A static path label which implements `Into<PathBuf>`.
+///
+/// Mirrors the const [`Label`](super::Label) ergonomics for compile-time constant paths.
+#[derive(Copy, Clone)]
+pub struct PathLabel {
+    segments: &'static [&'static str],
+}
+
+impl From<PathLabel> for PathBuf {
+    fn from(path: PathLabel) -> Self {
+        path.segments
+            .iter()
+            .copied()
+            .map(|segment| segment.parse().expect("path segment"))
+            .collect()
+    }
+}
+
+/// Return a [`PathLabel`] with the given static segments.
+pub const fn path_label(segments: &'static [&'static str]) -> PathLabel {
+    PathLabel { segments }
+}
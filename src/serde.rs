@@ -1,12 +1,47 @@
-use serde::de::{Deserialize, Deserializer, Error};
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::de::{Deserialize, Deserializer, Error, Visitor};
 use serde::ser::{Serialize, Serializer};
 
-use super::Id;
+use super::{Id, Map, PathBuf, Tuple};
+
+/// A [`Visitor`] which parses an [`Id`] from any string, owned string, or UTF-8 byte
+/// sequence, so that [`Id`] can be deserialized from non-borrowing and binary formats.
+struct IdVisitor;
+
+impl Visitor<'_> for IdVisitor {
+    type Value = Id;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a human-readable Id")
+    }
+
+    fn visit_str<E: Error>(self, s: &str) -> Result<Self::Value, E> {
+        s.parse().map_err(Error::custom)
+    }
+
+    fn visit_string<E: Error>(self, s: String) -> Result<Self::Value, E> {
+        self.visit_str(&s)
+    }
+
+    fn visit_bytes<E: Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        let s = std::str::from_utf8(bytes).map_err(Error::custom)?;
+        self.visit_str(s)
+    }
+
+    fn visit_byte_buf<E: Error>(self, bytes: Vec<u8>) -> Result<Self::Value, E> {
+        self.visit_bytes(&bytes)
+    }
+
+    fn visit_u64<E: Error>(self, u: u64) -> Result<Self::Value, E> {
+        Ok(Id::from(u))
+    }
+}
 
 impl<'de> Deserialize<'de> for Id {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        let inner: &str = Deserialize::deserialize(deserializer)?;
-        inner.parse().map_err(Error::custom)
+        deserializer.deserialize_str(IdVisitor)
     }
 }
 
@@ -15,3 +50,40 @@ impl Serialize for Id {
         self.as_str().serialize(serializer)
     }
 }
+
+impl<'de> Deserialize<'de> for PathBuf {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let path = String::deserialize(deserializer)?;
+        path.parse().map_err(Error::custom)
+    }
+}
+
+impl Serialize for PathBuf {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_string().serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Map<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        BTreeMap::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl<T: Serialize> Serialize for Map<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Tuple<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl<T: Serialize> Serialize for Tuple<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
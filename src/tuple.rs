@@ -0,0 +1,157 @@
+//! A generic ordered sequence of `T`, indexed by position.
+
+use std::fmt;
+use std::iter::FromIterator;
+use std::mem::size_of;
+use std::ops::{Deref, DerefMut};
+
+use get_size::GetSize;
+use safecast::TryCastFrom;
+
+/// An ordered sequence of `T`, the companion of [`Map`](super::Map).
+///
+/// A [`Tuple`] encodes as a sequence and hashes by folding over its members in order.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Tuple<T> {
+    inner: Vec<T>,
+}
+
+impl<T> Tuple<T> {
+    /// Construct a new, empty [`Tuple`].
+    pub fn new() -> Self {
+        Self { inner: Vec::new() }
+    }
+
+    /// Consume this [`Tuple`] and return the underlying [`Vec`].
+    pub fn into_inner(self) -> Vec<T> {
+        self.inner
+    }
+}
+
+impl<T> Default for Tuple<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Deref for Tuple<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> DerefMut for Tuple<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<T: GetSize> GetSize for Tuple<T> {
+    fn get_size(&self) -> usize {
+        size_of::<Vec<T>>() + self.inner.iter().map(GetSize::get_size).sum::<usize>()
+    }
+}
+
+impl<T> From<Vec<T>> for Tuple<T> {
+    fn from(inner: Vec<T>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T> FromIterator<T> for Tuple<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            inner: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> IntoIterator for Tuple<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Tuple<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
+
+impl<T, U, const N: usize> TryCastFrom<Tuple<T>> for [U; N]
+where
+    U: TryCastFrom<T>,
+{
+    fn can_cast_from(tuple: &Tuple<T>) -> bool {
+        tuple.len() == N && tuple.iter().all(U::can_cast_from)
+    }
+
+    fn opt_cast_from(tuple: Tuple<T>) -> Option<Self> {
+        if tuple.len() != N {
+            return None;
+        }
+
+        let mut cast = Vec::with_capacity(N);
+        for item in tuple.inner {
+            cast.push(U::opt_cast_from(item)?);
+        }
+
+        cast.try_into().ok()
+    }
+}
+
+macro_rules! cast_from_tuple {
+    ($len:expr; $(($i:tt, $t:ident)),+) => {
+        impl<T, $($t: TryCastFrom<T>),+> TryCastFrom<Tuple<T>> for ($($t,)+) {
+            fn can_cast_from(tuple: &Tuple<T>) -> bool {
+                tuple.len() == $len $(&& $t::can_cast_from(&tuple[$i]))+
+            }
+
+            fn opt_cast_from(tuple: Tuple<T>) -> Option<Self> {
+                if tuple.len() != $len {
+                    return None;
+                }
+
+                let mut iter = tuple.inner.into_iter();
+                Some(($($t::opt_cast_from(iter.next()?)?,)+))
+            }
+        }
+    };
+}
+
+cast_from_tuple!(1; (0, A));
+cast_from_tuple!(2; (0, A), (1, B));
+cast_from_tuple!(3; (0, A), (1, B), (2, C));
+cast_from_tuple!(4; (0, A), (1, B), (2, C), (3, D));
+cast_from_tuple!(5; (0, A), (1, B), (2, C), (3, D), (4, E));
+cast_from_tuple!(6; (0, A), (1, B), (2, C), (3, D), (4, E), (5, F));
+
+impl<T: fmt::Display> fmt::Debug for Tuple<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Tuple<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("(")?;
+
+        for (i, item) in self.inner.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+
+            fmt::Display::fmt(item, f)?;
+        }
+
+        f.write_str(")")
+    }
+}